@@ -5,9 +5,12 @@
 //! Template syntax: `{option1|option2|option3}` with nesting support.
 //! Example: `{fix|Fix}: {the|a} {bug|issue}` → 8 variations
 
+use digest::Output;
 use rayon::prelude::*;
 use sha1::{Digest, Sha1};
+use sha2::Sha256;
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
 use std::time::Instant;
 
 // =============================================================================
@@ -25,14 +28,48 @@ fn main() {
 
     let args = parse_args();
     let slots = expand_template(&args.template);
+    let target = args.target.resolve(args.algo);
 
-    validate_entropy(&slots);
-    print_mining_info(&slots, &args.target);
+    validate_entropy(&slots, args.time_window);
+    print_mining_info(&slots, &target, args.algo, args.time_window);
 
     let commit_header = build_commit_header(&args);
-    let target = parse_hex_target(&args.target);
 
-    match mine_vanity_hash(&slots, &commit_header, target) {
+    let checkpoint_spec = args.checkpoint.as_ref().map(|path| {
+        let key = checkpoint_key(&args.template, &commit_header, args.algo, args.time_window);
+        let (resume_bucket, resume_offset) = read_checkpoint(path, &key);
+        if resume_offset > 0 || resume_bucket > 0 {
+            eprintln!(
+                "Resuming from checkpoint: bucket {}, offset {}",
+                resume_bucket, resume_offset
+            );
+        }
+        CheckpointSpec {
+            path: path.clone(),
+            key,
+            resume_bucket,
+            resume_offset,
+        }
+    });
+
+    if checkpoint_spec.is_some() {
+        install_sigint_handler();
+    }
+
+    let result = match args.time_window {
+        Some(window) => {
+            mine_vanity_hash_time_sweep(&slots, &args, &target, window, checkpoint_spec.as_ref())
+        }
+        None => mine_vanity_hash(
+            &slots,
+            &commit_header,
+            &target,
+            args.algo,
+            checkpoint_spec.as_ref(),
+        ),
+    };
+
+    match result {
         Some(result) => print_success(&result),
         None => print_failure(),
     }
@@ -97,6 +134,16 @@ impl Slot {
         self.offsets.len()
     }
 
+    fn variation_len(&self, idx: usize) -> usize {
+        self.offsets[idx].1 as usize
+    }
+
+    fn variation_str(&self, idx: usize) -> &str {
+        let (off, len) = self.offsets[idx];
+        std::str::from_utf8(&self.data[off as usize..off as usize + len as usize])
+            .expect("template variations are UTF-8")
+    }
+
     /// Copy the selected variation into dest, return bytes written.
     /// SAFETY: idx must be < variation_count(), dest must have enough space.
     #[inline(always)]
@@ -113,6 +160,129 @@ struct MiningResult {
     hash: String,
     attempts: u64,
     duration_secs: f64,
+    /// The committer timestamp that produced the match, when mining swept a
+    /// `--time-window` rather than (or in addition to) the message.
+    committer_timestamp: Option<i64>,
+    /// The author timestamp used alongside `committer_timestamp`: the base
+    /// `--time-window` timestamp, unless `--sweep-author-time` also varied it.
+    author_timestamp: Option<i64>,
+    /// The `--timezone` the match was mined under, needed alongside
+    /// `committer_timestamp`/`author_timestamp` to reproduce the exact same
+    /// commit object (and therefore hash) via `GIT_COMMITTER_DATE`/`GIT_AUTHOR_DATE`.
+    timezone: Option<String>,
+}
+
+/// Git object hash function. Git repositories can use either the legacy
+/// SHA-1 object format or the newer SHA-256 one; the two are serialized
+/// identically aside from the digest algorithm.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Algo {
+    Sha1,
+    Sha256,
+}
+
+impl Algo {
+    fn parse(s: &str) -> Self {
+        match s {
+            "sha1" => Algo::Sha1,
+            "sha256" => Algo::Sha256,
+            other => {
+                eprintln!("Unknown --hash value: {} (expected sha1 or sha256)", other);
+                std::process::exit(1);
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for Algo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Algo::Sha1 => write!(f, "sha1"),
+            Algo::Sha256 => write!(f, "sha256"),
+        }
+    }
+}
+
+impl Algo {
+    /// Digest size in bytes: 20 for SHA-1, 32 for SHA-256.
+    fn digest_bytes(&self) -> usize {
+        match self {
+            Algo::Sha1 => 20,
+            Algo::Sha256 => 32,
+        }
+    }
+}
+
+/// A match target: the leading `bits` bits of a digest must equal `bytes`.
+/// Covers both hex-prefix targets (`c0deb055`) and difficulty targets
+/// (leading zero bits), since the latter is just an all-zero `bytes`.
+struct Target {
+    bytes: Vec<u8>,
+    bits: u32,
+    description: String,
+}
+
+impl Target {
+    /// Parse a hex prefix of any length, including an odd number of nibbles.
+    fn from_hex(hex: &str) -> Self {
+        let bits = hex.len() as u32 * 4;
+        let padded = if hex.len() % 2 == 1 {
+            format!("{}0", hex)
+        } else {
+            hex.to_string()
+        };
+
+        let bytes = (0..padded.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&padded[i..i + 2], 16).expect("Invalid target hex"))
+            .collect();
+
+        Self {
+            bytes,
+            bits,
+            description: format!("0x{} ({} bits)", hex, bits),
+        }
+    }
+
+    /// A target matching any digest whose leading `bits` bits are zero.
+    fn zero_bits(bits: u32) -> Self {
+        let bytes = vec![0u8; bits.div_ceil(8) as usize];
+        Self {
+            bytes,
+            bits,
+            description: format!("{} leading zero bits", bits),
+        }
+    }
+}
+
+/// CLI spec for a mining target, resolved into a [`Target`] once parsed.
+enum TargetSpec {
+    Hex(String),
+    Difficulty(u32),
+}
+
+impl TargetSpec {
+    /// Resolve against `algo`, exiting with a clean error if the target is
+    /// wider than that algorithm's digest - comparing more bits than the
+    /// hash produces can never match, and would otherwise panic by indexing
+    /// past the digest in `hash_matches_target`.
+    fn resolve(&self, algo: Algo) -> Target {
+        let target = match self {
+            TargetSpec::Hex(hex) => Target::from_hex(hex),
+            TargetSpec::Difficulty(bits) => Target::zero_bits(*bits),
+        };
+
+        let max_bits = algo.digest_bytes() as u32 * 8;
+        if target.bits > max_bits {
+            eprintln!(
+                "Target requires {} bits but --hash {} digests are only {} bits wide",
+                target.bits, algo, max_bits
+            );
+            std::process::exit(1);
+        }
+
+        target
+    }
 }
 
 // =============================================================================
@@ -178,7 +348,7 @@ fn parse_choice(bytes: &[u8], pos: &mut usize) -> Node {
     let mut alternatives = Vec::new();
 
     loop {
-        alternatives.push(parse_sequence(bytes, pos, &[b'|', b'}']));
+        alternatives.push(parse_sequence(bytes, pos, b"|}"));
 
         if *pos >= bytes.len() {
             panic!("Unclosed brace in template");
@@ -335,6 +505,83 @@ impl Odometer {
     }
 }
 
+// =============================================================================
+// Length Bucketing (for SHA-1 midstate caching)
+// =============================================================================
+//
+// SHA-1 midstate caching (see `Sha1Midstate`) only pays off when the bytes
+// preceding the message - "commit {len}\0" + header - are identical across
+// every attempt, which requires `len` (and therefore the message length) to
+// be constant. Template variations rarely all share one length, so instead
+// of mining over the original slots directly, we split them into buckets:
+// each bucket restricts every slot to variations of one particular length,
+// guaranteeing a constant total message length within that bucket. Buckets
+// are mined one at a time, in order, stopping at the first match.
+
+/// One midstate-cacheable mining pass: every slot restricted to variations
+/// of a single length, so every message this pass can produce is exactly
+/// `message_len` bytes.
+struct LengthBucket {
+    slots: Vec<Slot>,
+    message_len: usize,
+}
+
+/// Group a slot's variation indices by byte length.
+fn slot_length_groups(slot: &Slot) -> Vec<(usize, Vec<usize>)> {
+    let mut groups: Vec<(usize, Vec<usize>)> = Vec::new();
+
+    for idx in 0..slot.variation_count() {
+        let len = slot.variation_len(idx);
+        match groups.iter_mut().find(|(l, _)| *l == len) {
+            Some((_, indices)) => indices.push(idx),
+            None => groups.push((len, vec![idx])),
+        }
+    }
+
+    groups
+}
+
+/// Split `slots` into length buckets by taking the cartesian product of each
+/// slot's length groups. A slot whose variations are all the same length
+/// (the common case) contributes only one group, so most templates produce
+/// a single bucket equal to the original slots.
+fn build_length_buckets(slots: &[Slot]) -> Vec<LengthBucket> {
+    let per_slot_groups: Vec<Vec<(usize, Vec<usize>)>> = slots.iter().map(slot_length_groups).collect();
+
+    let mut buckets = Vec::new();
+    let mut combo = vec![0usize; slots.len()];
+
+    loop {
+        let mut bucket_slots = Vec::with_capacity(slots.len());
+        let mut message_len = 0;
+
+        for (slot, (group_idx, groups)) in slots.iter().zip(combo.iter().zip(&per_slot_groups)) {
+            let (len, indices) = &groups[*group_idx];
+            message_len += *len;
+            let variations = indices.iter().map(|&idx| slot.variation_str(idx).to_string()).collect();
+            bucket_slots.push(Slot::from_variations(variations));
+        }
+
+        buckets.push(LengthBucket {
+            slots: bucket_slots,
+            message_len,
+        });
+
+        let mut i = 0;
+        loop {
+            if i == combo.len() {
+                return buckets;
+            }
+            combo[i] += 1;
+            if combo[i] < per_slot_groups[i].len() {
+                break;
+            }
+            combo[i] = 0;
+            i += 1;
+        }
+    }
+}
+
 // =============================================================================
 // Commit Building & Hashing
 // =============================================================================
@@ -354,6 +601,49 @@ fn build_commit_header(args: &CliArgs) -> Vec<u8> {
     .into_bytes()
 }
 
+/// Unix timestamps are 10 decimal digits for the next couple of centuries
+/// (2001-09-09 through 2286-11-20), so a `--time-window` sweep never changes
+/// a timestamp's digit count. That lets [`build_commit_header_template`]
+/// reserve a fixed-width region for each timestamp and overwrite it in place
+/// per attempt instead of reformatting the whole header.
+const TIMESTAMP_WIDTH: usize = 10;
+
+/// Write `value` as `TIMESTAMP_WIDTH` ASCII decimal digits into `dest`, with
+/// no heap allocation.
+fn write_fixed_width_timestamp(dest: &mut [u8], value: i64) {
+    debug_assert!(
+        (10i64.pow(TIMESTAMP_WIDTH as u32 - 1)..10i64.pow(TIMESTAMP_WIDTH as u32)).contains(&value),
+        "timestamp {} does not fit {} digits",
+        value,
+        TIMESTAMP_WIDTH
+    );
+    let mut v = value as u64;
+    for slot in dest[..TIMESTAMP_WIDTH].iter_mut().rev() {
+        *slot = b'0' + (v % 10) as u8;
+        v /= 10;
+    }
+}
+
+/// Like [`build_commit_header`], but with the author/committer timestamp
+/// bytes left as a fixed-width placeholder, along with their byte offsets.
+/// Used while sweeping `--time-window`: the header is built once per thread
+/// and the timestamp fields are overwritten in place on each attempt via
+/// [`write_fixed_width_timestamp`], instead of reformatting the whole header
+/// from scratch in the hot loop.
+fn build_commit_header_template(args: &CliArgs) -> (Vec<u8>, usize, usize) {
+    let mut header = format!("tree {}\nparent {}\nauthor {} ", args.tree, args.parent, args.author).into_bytes();
+    let author_ts_offset = header.len();
+    header.extend_from_slice(&[b'0'; TIMESTAMP_WIDTH]);
+
+    header.extend_from_slice(format!(" {}\ncommitter {} ", args.timezone, args.author).as_bytes());
+    let committer_ts_offset = header.len();
+    header.extend_from_slice(&[b'0'; TIMESTAMP_WIDTH]);
+
+    header.extend_from_slice(format!(" {}\n\n", args.timezone).as_bytes());
+
+    (header, author_ts_offset, committer_ts_offset)
+}
+
 /// Build full git commit object: "commit {len}\0{header}{message}\n"
 #[inline(always)]
 fn build_commit_object(header: &[u8], message: &[u8], buffer: &mut [u8]) -> usize {
@@ -376,27 +666,186 @@ fn build_commit_object(header: &[u8], message: &[u8], buffer: &mut [u8]) -> usiz
 }
 
 #[inline(always)]
-fn hash_commit(data: &[u8]) -> [u8; 20] {
-    Sha1::digest(data).into()
+fn hash_commit<D: Digest>(data: &[u8]) -> Output<D> {
+    D::digest(data)
 }
 
 #[inline(always)]
-fn hash_matches_target(hash: &[u8; 20], target: u32) -> bool {
-    let prefix = u32::from_be_bytes([hash[0], hash[1], hash[2], hash[3]]);
-    prefix == target
+fn hash_matches_target(hash: &[u8], target: &Target) -> bool {
+    let full_bytes = (target.bits / 8) as usize;
+
+    if hash[..full_bytes] != target.bytes[..full_bytes] {
+        return false;
+    }
+
+    let remaining_bits = target.bits % 8;
+    if remaining_bits == 0 {
+        return true;
+    }
+
+    let mask = 0xFFu8 << (8 - remaining_bits);
+    hash[full_bytes] & mask == target.bytes[full_bytes] & mask
 }
 
-fn hash_to_hex(hash: &[u8; 20]) -> String {
+fn hash_to_hex(hash: &[u8]) -> String {
     hash.iter().map(|b| format!("{:02x}", b)).collect()
 }
 
+// =============================================================================
+// SHA-1 Midstate Caching
+// =============================================================================
+//
+// `"commit {len}\0" + header` is identical across every attempt within a
+// length bucket (see `LengthBucket`). SHA-1 processes input in 64-byte
+// blocks from a fixed initial state, so the complete blocks of that prefix
+// can be compressed once per bucket; each attempt then only needs to
+// compress the short remaining partial block plus the variable message and
+// padding, instead of rehashing the whole commit object. The `sha1` crate
+// doesn't expose its internal compression function, so it's reimplemented
+// here as a small, self-contained primitive.
+
+const SHA1_IV: [u32; 5] = [0x67452301, 0xEFCDAB89, 0x98BADCFE, 0x10325476, 0xC3D2E1F0];
+
+/// One step of the SHA-1 compression function over a single 64-byte block.
+fn sha1_compress(state: &mut [u32; 5], block: &[u8; 64]) {
+    let mut w = [0u32; 80];
+    for i in 0..16 {
+        w[i] = u32::from_be_bytes([block[4 * i], block[4 * i + 1], block[4 * i + 2], block[4 * i + 3]]);
+    }
+    for i in 16..80 {
+        w[i] = (w[i - 3] ^ w[i - 8] ^ w[i - 14] ^ w[i - 16]).rotate_left(1);
+    }
+
+    let (mut a, mut b, mut c, mut d, mut e) = (state[0], state[1], state[2], state[3], state[4]);
+
+    for (i, &wi) in w.iter().enumerate() {
+        let (f, k) = match i {
+            0..=19 => ((b & c) | (!b & d), 0x5A827999),
+            20..=39 => (b ^ c ^ d, 0x6ED9EBA1),
+            40..=59 => ((b & c) | (b & d) | (c & d), 0x8F1BBCDC),
+            _ => (b ^ c ^ d, 0xCA62C1D6),
+        };
+        let temp = a
+            .rotate_left(5)
+            .wrapping_add(f)
+            .wrapping_add(e)
+            .wrapping_add(k)
+            .wrapping_add(wi);
+        e = d;
+        d = c;
+        c = b.rotate_left(30);
+        b = a;
+        a = temp;
+    }
+
+    state[0] = state[0].wrapping_add(a);
+    state[1] = state[1].wrapping_add(b);
+    state[2] = state[2].wrapping_add(c);
+    state[3] = state[3].wrapping_add(d);
+    state[4] = state[4].wrapping_add(e);
+}
+
+/// SHA-1 state after compressing all complete 64-byte blocks of a constant
+/// prefix. `processed_bytes` is always a multiple of 64.
+struct Sha1Midstate {
+    state: [u32; 5],
+    processed_bytes: u64,
+}
+
+impl Sha1Midstate {
+    /// Precompute the midstate for `prefix`, returning it along with the
+    /// leftover bytes (< 64) that don't fill a complete block and so must
+    /// be hashed fresh, as part of the tail, on every attempt.
+    fn new(prefix: &[u8]) -> (Self, Vec<u8>) {
+        let full_blocks = prefix.len() / 64;
+        let mut state = SHA1_IV;
+
+        for i in 0..full_blocks {
+            let block: &[u8; 64] = prefix[i * 64..(i + 1) * 64].try_into().unwrap();
+            sha1_compress(&mut state, block);
+        }
+
+        let processed_bytes = (full_blocks * 64) as u64;
+        let leftover = prefix[processed_bytes as usize..].to_vec();
+
+        (Self { state, processed_bytes }, leftover)
+    }
+}
+
+/// Finish a SHA-1 digest from a cached midstate, given the remaining bytes
+/// of the message (leftover prefix bytes + variable content). Appends
+/// padding to `tail` in place.
+#[inline(always)]
+fn sha1_finalize(midstate: &Sha1Midstate, tail: &mut Vec<u8>) -> [u8; 20] {
+    let mut state = midstate.state;
+    let bit_len = (midstate.processed_bytes + tail.len() as u64) * 8;
+
+    tail.push(0x80);
+    while tail.len() % 64 != 56 {
+        tail.push(0);
+    }
+    tail.extend_from_slice(&bit_len.to_be_bytes());
+
+    for chunk in tail.chunks_exact(64) {
+        let block: &[u8; 64] = chunk.try_into().unwrap();
+        sha1_compress(&mut state, block);
+    }
+
+    let mut out = [0u8; 20];
+    for (i, word) in state.iter().enumerate() {
+        out[i * 4..i * 4 + 4].copy_from_slice(&word.to_be_bytes());
+    }
+    out
+}
+
 // =============================================================================
 // Mining
 // =============================================================================
 
 const CHUNK_SIZE: u64 = 1_000_000;
 
-fn mine_vanity_hash(slots: &[Slot], commit_header: &[u8], target: u32) -> Option<MiningResult> {
+/// Bundles the state every `mine_thread*` variant shares - the slots being
+/// enumerated, the match target, and the cross-thread coordination
+/// (counters, checkpoint) - so each variant's signature only has to add the
+/// handful of fields specific to its hashing strategy.
+#[derive(Clone, Copy)]
+struct MineContext<'a> {
+    slots: &'a [Slot],
+    counts: &'a [usize],
+    target: &'a Target,
+    num_threads: usize,
+    total_variations: u128,
+    start_offset: u64,
+    found: &'a AtomicBool,
+    attempts: &'a AtomicU64,
+    start: &'a Instant,
+    checkpoint: Option<&'a Checkpoint>,
+}
+
+fn mine_vanity_hash(
+    slots: &[Slot],
+    commit_header: &[u8],
+    target: &Target,
+    algo: Algo,
+    checkpoint: Option<&CheckpointSpec>,
+) -> Option<MiningResult> {
+    match algo {
+        // SHA-1 gets the midstate-caching fast path; SHA-256 will get the
+        // same treatment later, so it still rehashes the full commit object
+        // through the generic `Digest` path each attempt.
+        Algo::Sha1 => mine_vanity_hash_sha1(slots, commit_header, target, checkpoint),
+        Algo::Sha256 => mine_vanity_hash_with::<Sha256>(slots, commit_header, target, checkpoint),
+    }
+}
+
+/// Monomorphized per `D` so the hot hashing loop never pays for dynamic
+/// dispatch; `mine_vanity_hash` picks the instantiation once, up front.
+fn mine_vanity_hash_with<D: Digest>(
+    slots: &[Slot],
+    commit_header: &[u8],
+    target: &Target,
+    checkpoint: Option<&CheckpointSpec>,
+) -> Option<MiningResult> {
     let found = AtomicBool::new(false);
     let attempts = AtomicU64::new(0);
     let start = Instant::now();
@@ -405,65 +854,72 @@ fn mine_vanity_hash(slots: &[Slot], commit_header: &[u8], target: u32) -> Option
     let total = total_variations(slots);
     let counts = slot_counts(slots);
 
-    let result: Option<(String, String)> = (0..num_threads).into_par_iter().find_map_any(|tid| {
-        mine_thread(
-            slots,
-            &counts,
-            commit_header,
-            target,
-            tid,
-            num_threads,
-            total,
-            &found,
-            &attempts,
-            &start,
-        )
-    });
+    let start_offset = resume_offset_for_bucket(checkpoint, 0);
+    let cp_state = start_checkpoint(checkpoint, 0, num_threads, start_offset);
+
+    let ctx = MineContext {
+        slots,
+        counts: &counts,
+        target,
+        num_threads,
+        total_variations: total,
+        start_offset,
+        found: &found,
+        attempts: &attempts,
+        start: &start,
+        checkpoint: cp_state.as_deref(),
+    };
+
+    let result: Option<(String, String)> = (0..num_threads)
+        .into_par_iter()
+        .find_map_any(|tid| mine_thread::<D>(&ctx, tid, commit_header));
 
     let elapsed = start.elapsed().as_secs_f64();
     let total_attempts = attempts.load(Ordering::Relaxed);
 
+    finish_checkpoint(cp_state);
+
     result.map(|(message, hash)| MiningResult {
         message,
         hash,
         attempts: total_attempts,
         duration_secs: elapsed,
+        committer_timestamp: None,
+        author_timestamp: None,
+        timezone: None,
     })
 }
 
-fn mine_thread(
-    slots: &[Slot],
-    counts: &[usize],
-    commit_header: &[u8],
-    target: u32,
+fn mine_thread<D: Digest>(
+    ctx: &MineContext,
     thread_id: usize,
-    num_threads: usize,
-    total_variations: u128,
-    found: &AtomicBool,
-    attempts: &AtomicU64,
-    start: &Instant,
+    commit_header: &[u8],
 ) -> Option<(String, String)> {
-    let mut odometer = Odometer::new(counts.to_vec());
+    let mut odometer = Odometer::new(ctx.counts.to_vec());
     let mut message_buf = vec![0u8; 4096];
     let mut commit_buf = vec![0u8; 8192];
 
-    let mut offset = thread_id as u64 * CHUNK_SIZE;
+    let mut offset = ctx.start_offset + thread_id as u64 * CHUNK_SIZE;
 
     loop {
-        if found.load(Ordering::Relaxed) {
+        if ctx.found.load(Ordering::Relaxed) {
+            return None;
+        }
+
+        if offset as u128 >= ctx.total_variations {
             return None;
         }
 
         odometer.set_position(offset);
 
         for _ in 0..CHUNK_SIZE {
-            let msg_len = generate_message(slots, odometer.indices(), &mut message_buf);
+            let msg_len = generate_message(ctx.slots, odometer.indices(), &mut message_buf);
             let commit_len =
                 build_commit_object(commit_header, &message_buf[..msg_len], &mut commit_buf);
-            let hash = hash_commit(&commit_buf[..commit_len]);
+            let hash = hash_commit::<D>(&commit_buf[..commit_len]);
 
-            if hash_matches_target(&hash, target) {
-                found.store(true, Ordering::Relaxed);
+            if hash_matches_target(&hash, ctx.target) {
+                ctx.found.store(true, Ordering::Relaxed);
                 let message = String::from_utf8_lossy(&message_buf[..msg_len]).to_string();
                 return Some((message, hash_to_hex(&hash)));
             }
@@ -471,12 +927,13 @@ fn mine_thread(
             odometer.advance();
         }
 
-        let total = attempts.fetch_add(CHUNK_SIZE, Ordering::Relaxed) + CHUNK_SIZE;
-        offset += num_threads as u64 * CHUNK_SIZE;
+        let total = ctx.attempts.fetch_add(CHUNK_SIZE, Ordering::Relaxed) + CHUNK_SIZE;
+        offset += ctx.num_threads as u64 * CHUNK_SIZE;
 
-        report_progress(thread_id, total, start);
+        report_progress(thread_id, total, ctx.start);
+        record_checkpoint(ctx.checkpoint, thread_id, offset, total);
 
-        if offset as u128 >= total_variations {
+        if offset as u128 >= ctx.total_variations {
             return None;
         }
     }
@@ -490,6 +947,436 @@ fn report_progress(thread_id: usize, total: u64, start: &Instant) {
     }
 }
 
+/// SHA-1 mining with midstate caching: mine each length bucket in turn,
+/// stopping at the first match. Attempts and elapsed time accumulate across
+/// buckets so `MiningResult` reports totals for the whole search.
+fn mine_vanity_hash_sha1(
+    slots: &[Slot],
+    commit_header: &[u8],
+    target: &Target,
+    checkpoint: Option<&CheckpointSpec>,
+) -> Option<MiningResult> {
+    let buckets = build_length_buckets(slots);
+    let start = Instant::now();
+    let mut total_attempts = 0u64;
+    let resume_bucket = checkpoint.map(|cp| cp.resume_bucket).unwrap_or(0);
+
+    for (bucket_idx, bucket) in buckets.iter().enumerate() {
+        // Buckets before the one recorded in the checkpoint were fully
+        // exhausted by a prior run; skip straight to where it left off.
+        if bucket_idx < resume_bucket {
+            continue;
+        }
+
+        let content_len = commit_header.len() + bucket.message_len + 1;
+        let mut prefix = format!("commit {}\x00", content_len).into_bytes();
+        prefix.extend_from_slice(commit_header);
+
+        let (midstate, prefix_tail) = Sha1Midstate::new(&prefix);
+
+        let found = AtomicBool::new(false);
+        let attempts = AtomicU64::new(0);
+        let num_threads = rayon::current_num_threads();
+        let total = total_variations(&bucket.slots);
+        let counts = slot_counts(&bucket.slots);
+
+        let start_offset = resume_offset_for_bucket(checkpoint, bucket_idx);
+        let cp_state = start_checkpoint(checkpoint, bucket_idx, num_threads, start_offset);
+
+        let ctx = MineContext {
+            slots: &bucket.slots,
+            counts: &counts,
+            target,
+            num_threads,
+            total_variations: total,
+            start_offset,
+            found: &found,
+            attempts: &attempts,
+            start: &start,
+            checkpoint: cp_state.as_deref(),
+        };
+
+        let result: Option<(String, String)> = (0..num_threads)
+            .into_par_iter()
+            .find_map_any(|tid| mine_thread_sha1_midstate(&ctx, tid, &midstate, &prefix_tail));
+
+        total_attempts += attempts.load(Ordering::Relaxed);
+
+        finish_checkpoint(cp_state);
+
+        if let Some((message, hash)) = result {
+            return Some(MiningResult {
+                message,
+                hash,
+                attempts: total_attempts,
+                duration_secs: start.elapsed().as_secs_f64(),
+                committer_timestamp: None,
+                author_timestamp: None,
+                timezone: None,
+            });
+        }
+    }
+
+    None
+}
+
+fn mine_thread_sha1_midstate(
+    ctx: &MineContext,
+    thread_id: usize,
+    midstate: &Sha1Midstate,
+    prefix_tail: &[u8],
+) -> Option<(String, String)> {
+    let mut odometer = Odometer::new(ctx.counts.to_vec());
+    let mut message_buf = vec![0u8; 4096];
+
+    let mut offset = ctx.start_offset + thread_id as u64 * CHUNK_SIZE;
+
+    loop {
+        if ctx.found.load(Ordering::Relaxed) {
+            return None;
+        }
+
+        if offset as u128 >= ctx.total_variations {
+            return None;
+        }
+
+        odometer.set_position(offset);
+
+        for _ in 0..CHUNK_SIZE {
+            let msg_len = generate_message(ctx.slots, odometer.indices(), &mut message_buf);
+
+            let mut tail = Vec::with_capacity(prefix_tail.len() + msg_len + 1 + 64);
+            tail.extend_from_slice(prefix_tail);
+            tail.extend_from_slice(&message_buf[..msg_len]);
+            tail.push(b'\n');
+
+            let hash = sha1_finalize(midstate, &mut tail);
+
+            if hash_matches_target(&hash, ctx.target) {
+                ctx.found.store(true, Ordering::Relaxed);
+                let message = String::from_utf8_lossy(&message_buf[..msg_len]).to_string();
+                return Some((message, hash_to_hex(&hash)));
+            }
+
+            odometer.advance();
+        }
+
+        let total = ctx.attempts.fetch_add(CHUNK_SIZE, Ordering::Relaxed) + CHUNK_SIZE;
+        offset += ctx.num_threads as u64 * CHUNK_SIZE;
+
+        report_progress(thread_id, total, ctx.start);
+        record_checkpoint(ctx.checkpoint, thread_id, offset, total);
+
+        if offset as u128 >= ctx.total_variations {
+            return None;
+        }
+    }
+}
+
+/// Sweep the committer timestamp over a `--time-window` instead of (or
+/// alongside) the template, as an extra entropy dimension. The header is no
+/// longer constant across attempts, so this bypasses the SHA-1 midstate fast
+/// path and rehashes the full commit object every attempt via the generic
+/// `Digest` path, same as a non-SHA-1 `Algo`.
+fn mine_vanity_hash_time_sweep(
+    slots: &[Slot],
+    args: &CliArgs,
+    target: &Target,
+    window: u64,
+    checkpoint: Option<&CheckpointSpec>,
+) -> Option<MiningResult> {
+    match args.algo {
+        Algo::Sha1 => {
+            mine_vanity_hash_time_sweep_with::<Sha1>(slots, args, target, window, checkpoint)
+        }
+        Algo::Sha256 => {
+            mine_vanity_hash_time_sweep_with::<Sha256>(slots, args, target, window, checkpoint)
+        }
+    }
+}
+
+fn mine_vanity_hash_time_sweep_with<D: Digest>(
+    slots: &[Slot],
+    args: &CliArgs,
+    target: &Target,
+    window: u64,
+    checkpoint: Option<&CheckpointSpec>,
+) -> Option<MiningResult> {
+    let base_timestamp: i64 = args
+        .timestamp
+        .parse()
+        .expect("--time-window requires an integer <timestamp>");
+
+    let found = AtomicBool::new(false);
+    let attempts = AtomicU64::new(0);
+    let start = Instant::now();
+
+    let num_threads = rayon::current_num_threads();
+    let mut counts = slot_counts(slots);
+    counts.push(window as usize);
+    let total = total_variations(slots) * window as u128;
+
+    let start_offset = resume_offset_for_bucket(checkpoint, 0);
+    let cp_state = start_checkpoint(checkpoint, 0, num_threads, start_offset);
+
+    let ctx = MineContext {
+        slots,
+        counts: &counts,
+        target,
+        num_threads,
+        total_variations: total,
+        start_offset,
+        found: &found,
+        attempts: &attempts,
+        start: &start,
+        checkpoint: cp_state.as_deref(),
+    };
+
+    let result: Option<(String, String, i64, i64)> = (0..num_threads)
+        .into_par_iter()
+        .find_map_any(|tid| mine_thread_time_sweep::<D>(&ctx, tid, args, base_timestamp));
+
+    let elapsed = start.elapsed().as_secs_f64();
+    let total_attempts = attempts.load(Ordering::Relaxed);
+
+    finish_checkpoint(cp_state);
+
+    result.map(|(message, hash, committer_timestamp, author_timestamp)| MiningResult {
+        message,
+        hash,
+        attempts: total_attempts,
+        duration_secs: elapsed,
+        committer_timestamp: Some(committer_timestamp),
+        author_timestamp: Some(author_timestamp),
+        timezone: Some(args.timezone.clone()),
+    })
+}
+
+fn mine_thread_time_sweep<D: Digest>(
+    ctx: &MineContext,
+    thread_id: usize,
+    args: &CliArgs,
+    base_timestamp: i64,
+) -> Option<(String, String, i64, i64)> {
+    let mut odometer = Odometer::new(ctx.counts.to_vec());
+    let mut message_buf = vec![0u8; 4096];
+    let mut commit_buf = vec![0u8; 8192];
+
+    let (mut header, author_ts_offset, committer_ts_offset) = build_commit_header_template(args);
+    write_fixed_width_timestamp(&mut header[author_ts_offset..], base_timestamp);
+    write_fixed_width_timestamp(&mut header[committer_ts_offset..], base_timestamp);
+
+    let mut offset = ctx.start_offset + thread_id as u64 * CHUNK_SIZE;
+
+    loop {
+        if ctx.found.load(Ordering::Relaxed) {
+            return None;
+        }
+
+        if offset as u128 >= ctx.total_variations {
+            return None;
+        }
+
+        odometer.set_position(offset);
+
+        for _ in 0..CHUNK_SIZE {
+            let indices = odometer.indices();
+            let committer_timestamp = base_timestamp + indices[ctx.slots.len()] as i64;
+            let author_timestamp = if args.sweep_author {
+                committer_timestamp
+            } else {
+                base_timestamp
+            };
+
+            write_fixed_width_timestamp(&mut header[committer_ts_offset..], committer_timestamp);
+            if args.sweep_author {
+                write_fixed_width_timestamp(&mut header[author_ts_offset..], author_timestamp);
+            }
+
+            let msg_len = generate_message(ctx.slots, indices, &mut message_buf);
+            let commit_len =
+                build_commit_object(&header, &message_buf[..msg_len], &mut commit_buf);
+            let hash = hash_commit::<D>(&commit_buf[..commit_len]);
+
+            if hash_matches_target(&hash, ctx.target) {
+                ctx.found.store(true, Ordering::Relaxed);
+                let message = String::from_utf8_lossy(&message_buf[..msg_len]).to_string();
+                return Some((message, hash_to_hex(&hash), committer_timestamp, author_timestamp));
+            }
+
+            odometer.advance();
+        }
+
+        let total = ctx.attempts.fetch_add(CHUNK_SIZE, Ordering::Relaxed) + CHUNK_SIZE;
+        offset += ctx.num_threads as u64 * CHUNK_SIZE;
+
+        report_progress(thread_id, total, ctx.start);
+        record_checkpoint(ctx.checkpoint, thread_id, offset, total);
+
+        if offset as u128 >= ctx.total_variations {
+            return None;
+        }
+    }
+}
+
+// =============================================================================
+// Checkpointing
+// =============================================================================
+
+/// Guards against resuming a `--checkpoint` file against a different search:
+/// a hash of the template, the commit header it's mined against, and the
+/// algorithm/time-window settings that shape how flat offsets are bucketed.
+fn checkpoint_key(template: &str, commit_header: &[u8], algo: Algo, time_window: Option<u64>) -> String {
+    let mut data = template.as_bytes().to_vec();
+    data.push(0);
+    data.extend_from_slice(commit_header);
+    data.push(0);
+    data.extend_from_slice(algo.to_string().as_bytes());
+    data.push(0);
+    data.extend_from_slice(time_window.unwrap_or(0).to_string().as_bytes());
+    hash_to_hex(&Sha256::digest(&data))
+}
+
+/// Parsed `--checkpoint` configuration for this run: where the checkpoint
+/// file lives, the guard key for this search, and where a prior run left
+/// off (bucket 0, offset 0 if there's nothing to resume).
+struct CheckpointSpec {
+    path: String,
+    key: String,
+    resume_bucket: usize,
+    resume_offset: u64,
+}
+
+/// Live, periodically-flushed progress for the length bucket currently being
+/// mined. Each thread records the lowest offset it hasn't yet exhausted;
+/// `flush` persists the minimum across threads, since that's the one offset
+/// a resumed run must not skip past.
+struct Checkpoint {
+    path: String,
+    key: String,
+    bucket: usize,
+    thread_offsets: Vec<AtomicU64>,
+}
+
+impl Checkpoint {
+    fn new(path: String, key: String, bucket: usize, num_threads: usize, start_offset: u64) -> Self {
+        Checkpoint {
+            path,
+            key,
+            bucket,
+            thread_offsets: (0..num_threads).map(|_| AtomicU64::new(start_offset)).collect(),
+        }
+    }
+
+    fn record(&self, thread_id: usize, offset: u64) {
+        self.thread_offsets[thread_id].store(offset, Ordering::Relaxed);
+    }
+
+    fn flush(&self) {
+        let offset = self
+            .thread_offsets
+            .iter()
+            .map(|o| o.load(Ordering::Relaxed))
+            .min()
+            .unwrap_or(0);
+        let contents = format!("{}\n{}\n{}\n", self.key, self.bucket, offset);
+        if let Err(e) = std::fs::write(&self.path, contents) {
+            eprintln!("Warning: failed to write checkpoint to {}: {}", self.path, e);
+        }
+    }
+}
+
+/// Shared with the SIGINT handler so it can flush whichever bucket is
+/// currently being mined without threading a reference through every call.
+static CURRENT_CHECKPOINT: Mutex<Option<Arc<Checkpoint>>> = Mutex::new(None);
+
+fn set_current_checkpoint(checkpoint: Option<Arc<Checkpoint>>) {
+    *CURRENT_CHECKPOINT.lock().unwrap() = checkpoint;
+}
+
+/// Read a checkpoint file written by a prior run. Returns `(0, 0)` (mine from
+/// the start) if the file doesn't exist or its key doesn't match this search.
+fn read_checkpoint(path: &str, key: &str) -> (usize, u64) {
+    let contents = match std::fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(_) => return (0, 0),
+    };
+
+    let mut lines = contents.lines();
+    let file_key = lines.next().unwrap_or("");
+    if file_key != key {
+        eprintln!(
+            "Warning: checkpoint at {} doesn't match this template/header; starting over",
+            path
+        );
+        return (0, 0);
+    }
+
+    let bucket = lines.next().and_then(|s| s.parse().ok()).unwrap_or(0);
+    let offset = lines.next().and_then(|s| s.parse().ok()).unwrap_or(0);
+    (bucket, offset)
+}
+
+/// The offset to resume `bucket_idx` from: the checkpointed offset if it's
+/// the bucket a prior run was still searching, otherwise 0 (earlier buckets
+/// are skipped entirely; later ones haven't been touched yet).
+fn resume_offset_for_bucket(checkpoint: Option<&CheckpointSpec>, bucket_idx: usize) -> u64 {
+    match checkpoint {
+        Some(cp) if cp.resume_bucket == bucket_idx => cp.resume_offset,
+        _ => 0,
+    }
+}
+
+fn start_checkpoint(
+    checkpoint: Option<&CheckpointSpec>,
+    bucket: usize,
+    num_threads: usize,
+    start_offset: u64,
+) -> Option<Arc<Checkpoint>> {
+    checkpoint.map(|cp| {
+        let state = Arc::new(Checkpoint::new(
+            cp.path.clone(),
+            cp.key.clone(),
+            bucket,
+            num_threads,
+            start_offset,
+        ));
+        set_current_checkpoint(Some(Arc::clone(&state)));
+        state
+    })
+}
+
+fn finish_checkpoint(checkpoint: Option<Arc<Checkpoint>>) {
+    if let Some(cp) = checkpoint {
+        cp.flush();
+        set_current_checkpoint(None);
+    }
+}
+
+/// Record this thread's progress and, at the same cadence as
+/// `report_progress`, persist the bucket's lowest not-yet-exhausted offset.
+fn record_checkpoint(checkpoint: Option<&Checkpoint>, thread_id: usize, offset: u64, total: u64) {
+    if let Some(cp) = checkpoint {
+        cp.record(thread_id, offset);
+        if thread_id == 0 && total % 100_000_000 < CHUNK_SIZE {
+            cp.flush();
+        }
+    }
+}
+
+/// Flushes the in-progress checkpoint and exits on Ctrl-C, so a long mine can
+/// be killed and picked back up later via `--checkpoint`.
+fn install_sigint_handler() {
+    ctrlc::set_handler(|| {
+        if let Some(cp) = CURRENT_CHECKPOINT.lock().unwrap().as_ref() {
+            cp.flush();
+            eprintln!("\nInterrupted — checkpoint saved to {}", cp.path);
+        }
+        std::process::exit(130);
+    })
+    .expect("Error installing Ctrl-C handler");
+}
+
 // =============================================================================
 // CLI
 // =============================================================================
@@ -501,62 +1388,155 @@ struct CliArgs {
     author: String,
     timestamp: String,
     timezone: String,
-    target: String,
+    target: TargetSpec,
+    algo: Algo,
+    time_window: Option<u64>,
+    sweep_author: bool,
+    checkpoint: Option<String>,
 }
 
 fn parse_args() -> CliArgs {
-    let args: Vec<String> = std::env::args().collect();
+    let raw: Vec<String> = std::env::args().skip(1).collect();
+
+    let mut algo = Algo::Sha1;
+    let mut difficulty: Option<u32> = None;
+    let mut time_window: Option<u64> = None;
+    let mut sweep_author = false;
+    let mut checkpoint: Option<String> = None;
+    let mut positional = Vec::new();
 
-    if args.len() != 8 {
+    let mut i = 0;
+    while i < raw.len() {
+        match raw[i].as_str() {
+            "--hash" => {
+                let value = raw.get(i + 1).unwrap_or_else(|| {
+                    print_usage();
+                    std::process::exit(1);
+                });
+                algo = Algo::parse(value);
+                i += 2;
+            }
+            "--difficulty" => {
+                let value = raw.get(i + 1).unwrap_or_else(|| {
+                    print_usage();
+                    std::process::exit(1);
+                });
+                difficulty = Some(value.parse().unwrap_or_else(|_| {
+                    eprintln!("Invalid --difficulty value: {} (expected an integer)", value);
+                    std::process::exit(1);
+                }));
+                i += 2;
+            }
+            "--time-window" => {
+                let value = raw.get(i + 1).unwrap_or_else(|| {
+                    print_usage();
+                    std::process::exit(1);
+                });
+                time_window = Some(value.parse().unwrap_or_else(|_| {
+                    eprintln!("Invalid --time-window value: {} (expected a positive integer)", value);
+                    std::process::exit(1);
+                }));
+                i += 2;
+            }
+            "--sweep-author-time" => {
+                sweep_author = true;
+                i += 1;
+            }
+            "--checkpoint" => {
+                let value = raw.get(i + 1).unwrap_or_else(|| {
+                    print_usage();
+                    std::process::exit(1);
+                });
+                checkpoint = Some(value.clone());
+                i += 2;
+            }
+            other => {
+                positional.push(other.to_string());
+                i += 1;
+            }
+        }
+    }
+
+    // In --difficulty mode the trailing hex target is omitted entirely.
+    let expected_positional = if difficulty.is_some() { 6 } else { 7 };
+    if positional.len() != expected_positional {
         print_usage();
         std::process::exit(1);
     }
 
+    let target = match difficulty {
+        Some(bits) => TargetSpec::Difficulty(bits),
+        None => TargetSpec::Hex(positional[6].clone()),
+    };
+
     CliArgs {
-        template: args[1].clone(),
-        tree: args[2].clone(),
-        parent: args[3].clone(),
-        author: args[4].clone(),
-        timestamp: args[5].clone(),
-        timezone: args[6].clone(),
-        target: args[7].clone(),
+        template: positional[0].clone(),
+        tree: positional[1].clone(),
+        parent: positional[2].clone(),
+        author: positional[3].clone(),
+        timestamp: positional[4].clone(),
+        timezone: positional[5].clone(),
+        target,
+        algo,
+        time_window,
+        sweep_author,
+        checkpoint,
     }
 }
 
 fn print_usage() {
     eprintln!(
-        "Usage: codeboss <template> <tree> <parent> <author> <timestamp> <timezone> <target>"
+        "Usage: codeboss [--hash sha1|sha256] [--time-window secs] [--sweep-author-time] [--checkpoint file] <template> <tree> <parent> <author> <timestamp> <timezone> <target>"
+    );
+    eprintln!(
+        "       codeboss [--hash sha1|sha256] --difficulty <bits> [--checkpoint file] <template> <tree> <parent> <author> <timestamp> <timezone>"
     );
     eprintln!(
         "Example: codeboss '{{fix|Fix}}: typo' abc123 def456 'Name <email>' 1234567890 +0000 c0deb055"
     );
-}
-
-fn parse_hex_target(target: &str) -> u32 {
-    u32::from_str_radix(target, 16).expect("Invalid target hex")
+    eprintln!("        codeboss --difficulty 24 '{{fix|Fix}}: typo' abc123 def456 'Name <email>' 1234567890 +0000");
+    eprintln!("        codeboss --time-window 86400 '{{fix|Fix}}: typo' abc123 def456 'Name <email>' 1234567890 +0000 c0deb055");
+    eprintln!("        codeboss --checkpoint progress.txt '{{fix|Fix}}: typo' abc123 def456 'Name <email>' 1234567890 +0000 c0deb055");
 }
 
 // =============================================================================
 // Output
 // =============================================================================
 
-fn validate_entropy(slots: &[Slot]) {
-    let bits = entropy_bits(slots);
+fn validate_entropy(slots: &[Slot], time_window: Option<u64>) {
+    let mut bits = entropy_bits(slots);
+    if let Some(window) = time_window {
+        bits += (window as f64).log2();
+    }
+
     if bits < 37.0 {
         eprintln!("❌ ERROR: Template has only {:.1} bits of entropy", bits);
         eprintln!("   Minimum required: 37 bits");
-        eprintln!("   Add more variations to your template");
+        eprintln!("   Add more variations to your template, or a --time-window");
         std::process::exit(2);
     }
 }
 
-fn print_mining_info(slots: &[Slot], target: &str) {
+fn print_mining_info(slots: &[Slot], target: &Target, algo: Algo, time_window: Option<u64>) {
     eprintln!(
         "Template: {} variations ({:.1} bits)",
         total_variations(slots),
         entropy_bits(slots)
     );
-    eprintln!("Target: {}", target);
+    if let Some(window) = time_window {
+        eprintln!(
+            "Time window: {} seconds ({:.1} bits)",
+            window,
+            (window as f64).log2()
+        );
+    }
+    eprintln!("Target: {}", target.description);
+    eprintln!(
+        "Expected attempts: ~2^{} ({:.2e})",
+        target.bits,
+        2f64.powi(target.bits as i32)
+    );
+    eprintln!("Algorithm: {}", algo);
     eprintln!("Threads: {}", rayon::current_num_threads());
     eprintln!();
 }
@@ -569,6 +1549,16 @@ fn print_success(result: &MiningResult) {
         result.duration_secs, result.attempts, rate
     );
     eprintln!("Hash: {}", result.hash);
+    if let (Some(committer_ts), Some(author_ts), Some(tz)) =
+        (result.committer_timestamp, result.author_timestamp, &result.timezone)
+    {
+        eprintln!("Committer timestamp: {}", committer_ts);
+        eprintln!("Author timestamp: {}", author_ts);
+        eprintln!(
+            "Reproduce with: GIT_AUTHOR_DATE=\"{} {}\" GIT_COMMITTER_DATE=\"{} {}\" git commit ...",
+            author_ts, tz, committer_ts, tz
+        );
+    }
     println!("{}", result.message);
 }
 